@@ -0,0 +1,77 @@
+//! Android foldable posture support.
+//!
+//! `read_angle` does not read the hinge: it always returns
+//! `Error::NotImplemented`, so on Android `read_hinge_angle` and
+//! `read_posture_type` never yield an angle or a posture. The Kotlin
+//! class `dev.kami.HingeBridge` (a wrapper around androidx.window's
+//! `WindowInfoTracker`/`FoldingFeature`) that `call_bridge` reaches over
+//! JNI is not in this repo, which has no generated Android project (no
+//! `gen/android`, no Gradle module). The distinct error kind keeps a
+//! missing bridge from being confused with a transient sensor glitch
+//! (`Error::Read`) on the frontend. `call_bridge` maps the bridge's
+//! answer onto the same `f64` angle contract the macOS sensor uses, but
+//! `read_angle` does not call it.
+
+use jni::objects::{JObject, JValue};
+
+use crate::Error;
+
+/// Angle substituted when the platform only reports a discrete fold
+/// state instead of a precise hinge angle. Each lies inside the
+/// `PostureType` range its state maps to: 180° is the middle of
+/// `Continuous`, 90° falls within `HalfOpened` (30°..170°).
+const FLAT_ANGLE_DEG: f64 = 180.0;
+const HALF_OPENED_ANGLE_DEG: f64 = 90.0;
+
+pub(crate) fn read_angle() -> Result<f64, Error> {
+    Err(Error::NotImplemented(
+        "dev.kami.HingeBridge has not landed yet".into(),
+    ))
+}
+
+/// Reads the current posture from `dev.kami.HingeBridge`.
+///
+/// `currentAngleDegrees` returns the `FoldingFeature`'s hinge angle when
+/// the device reports one, or a negative value when only the coarser
+/// flat/half-opened hint (`currentFoldState`) is available.
+///
+/// Not yet called from `read_angle` — see the module docs.
+#[allow(dead_code)]
+fn call_bridge() -> Result<f64, Error> {
+    let ctx = ndk_context::android_context();
+    let vm = unsafe { jni::JavaVM::from_raw(ctx.vm().cast()) }
+        .map_err(|err| Error::SensorInit(err.to_string()))?;
+    let mut env = vm
+        .attach_current_thread()
+        .map_err(|err| Error::SensorInit(err.to_string()))?;
+    let activity = unsafe { JObject::from_raw(ctx.context().cast()) };
+
+    let angle = env
+        .call_static_method(
+            "dev/kami/HingeBridge",
+            "currentAngleDegrees",
+            "(Landroid/app/Activity;)D",
+            &[JValue::Object(&activity)],
+        )
+        .and_then(|value| value.d())
+        .map_err(|err| Error::Read(err.to_string()))?;
+
+    if angle >= 0.0 {
+        return Ok(angle);
+    }
+
+    let fold_state = env
+        .call_static_method(
+            "dev/kami/HingeBridge",
+            "currentFoldState",
+            "(Landroid/app/Activity;)I",
+            &[JValue::Object(&activity)],
+        )
+        .and_then(|value| value.i())
+        .map_err(|err| Error::Read(err.to_string()))?;
+
+    Ok(match fold_state {
+        1 => HALF_OPENED_ANGLE_DEG,
+        _ => FLAT_ANGLE_DEG,
+    })
+}
@@ -1,7 +1,51 @@
 use std::fmt;
+use std::time::Duration;
 
 use hinge_angle::HingeAngle;
+use serde::Serialize;
+use tauri::async_runtime::{JoinHandle, Mutex};
+use tauri::{AppHandle, Emitter, Manager, State};
 
+/// Typed command error, serialized as `{ "kind": "...", "message": "..." }`
+/// so the frontend can branch on `kind` instead of matching on message text.
+#[derive(Debug, thiserror::Error)]
+enum Error {
+    #[error("platform not supported")]
+    Unsupported,
+    #[error("sensor initialization failed: {0}")]
+    SensorInit(String),
+    #[error("failed to read hinge sensor: {0}")]
+    Read(String),
+    #[error("not yet implemented: {0}")]
+    NotImplemented(String),
+}
+
+impl Error {
+    fn kind(&self) -> &'static str {
+        match self {
+            Error::Unsupported => "unsupported",
+            Error::SensorInit(_) => "sensorInit",
+            Error::Read(_) => "read",
+            Error::NotImplemented(_) => "notImplemented",
+        }
+    }
+}
+
+impl Serialize for Error {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+
+        let mut state = serializer.serialize_struct("Error", 2)?;
+        state.serialize_field("kind", self.kind())?;
+        state.serialize_field("message", &self.to_string())?;
+        state.end()
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
 enum PostureType {
     Continuous,
     Folded,
@@ -10,6 +54,11 @@ enum PostureType {
 }
 
 impl PostureType {
+    /// Classifies a raw hinge angle with no memory of prior readings.
+    ///
+    /// Stateless and hard-cutoff: a reading that sits exactly on a
+    /// boundary can flip on the next near-identical reading. Use
+    /// [`PostureTracker`] when flapping at the boundaries matters.
     fn from_angle(angle_deg: f64) -> Self {
         let normalized = ((angle_deg % 360.0) + 360.0) % 360.0;
         if (170.0..=190.0).contains(&normalized) {
@@ -25,6 +74,75 @@ impl PostureType {
     }
 }
 
+/// Default hysteresis margin, in degrees, a reading must cross a posture
+/// boundary by before [`PostureTracker`] re-classifies.
+const DEFAULT_HYSTERESIS_DEG: f64 = 5.0;
+
+/// Stateful wrapper around [`PostureType::from_angle`] that only changes
+/// posture once the angle has left the previous posture's boundary by
+/// more than `margin_deg`, so a hinge resting near a boundary (e.g.
+/// 190.0) doesn't flap between postures on every reading.
+///
+/// Invariant: equal or near-equal consecutive readings never produce a
+/// transition, because the previous posture's range is widened by
+/// `margin_deg` before the new angle is tested against it.
+struct PostureTracker {
+    last: Option<PostureType>,
+    margin_deg: f64,
+}
+
+impl Default for PostureTracker {
+    fn default() -> Self {
+        Self::with_margin(DEFAULT_HYSTERESIS_DEG)
+    }
+}
+
+impl PostureTracker {
+    fn with_margin(margin_deg: f64) -> Self {
+        Self {
+            last: None,
+            margin_deg,
+        }
+    }
+
+    /// Classifies `angle_deg`, staying in the previous posture while the
+    /// angle is still within its boundary widened by `margin_deg`, and
+    /// falling back to [`PostureType::from_angle`] otherwise (including
+    /// on the very first reading).
+    fn next(&mut self, angle_deg: f64) -> PostureType {
+        let normalized = ((angle_deg % 360.0) + 360.0) % 360.0;
+
+        if let Some(last) = self.last {
+            if Self::within_margin(last, normalized, self.margin_deg) {
+                return last;
+            }
+        }
+
+        let posture = PostureType::from_angle(normalized);
+        self.last = Some(posture);
+        posture
+    }
+
+    /// Whether `normalized` still falls within `posture`'s boundary once
+    /// widened by `margin_deg` on each side.
+    fn within_margin(posture: PostureType, normalized: f64, margin_deg: f64) -> bool {
+        match posture {
+            PostureType::Folded => {
+                normalized <= 30.0 + margin_deg || normalized >= 350.0 - margin_deg
+            }
+            PostureType::HalfOpened => {
+                normalized > 30.0 - margin_deg && normalized < 170.0 + margin_deg
+            }
+            PostureType::Continuous => {
+                normalized >= 170.0 - margin_deg && normalized <= 190.0 + margin_deg
+            }
+            PostureType::Flipped => {
+                normalized > 190.0 - margin_deg && normalized < 350.0 + margin_deg
+            }
+        }
+    }
+}
+
 impl fmt::Display for PostureType {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
@@ -38,52 +156,160 @@ impl fmt::Display for PostureType {
 }
 
 #[cfg(target_os = "macos")]
-async fn read_platform_hinge_angle() -> Result<f64, String> {
-    use std::sync::OnceLock;
-
-    use hinge_angle::macos::Hinge;
-    use tauri::async_runtime::Mutex;
-
-    static SENSOR: OnceLock<Result<Mutex<Hinge>, hinge_angle::macos::Error>> = OnceLock::new();
+mod macos;
 
-    let hinge = SENSOR.get_or_init(|| Hinge::new().map(Mutex::new));
+#[cfg(target_os = "macos")]
+async fn read_platform_hinge_angle() -> Result<f64, Error> {
+    macos::read_angle().await
+}
 
-    let angle = hinge
-        .as_ref()
-        .map_err(|err| err.to_string())?
-        .lock()
-        .await
-        .angle()
-        .map_err(|err| err.to_string())?;
+#[cfg(target_os = "android")]
+mod android;
 
-    Ok(angle as f64)
+// `read_angle` is currently an instant `Err` (see the `android` module
+// docs), so no `spawn_blocking` is needed; reintroduce it once the JNI
+// call is actually wired in.
+#[cfg(target_os = "android")]
+async fn read_platform_hinge_angle() -> Result<f64, Error> {
+    android::read_angle()
 }
 
-#[cfg(not(target_os = "macos"))]
-fn read_platform_hinge_angle() -> Result<f64, String> {
-    Err("Platform not supported")
+#[cfg(not(any(target_os = "macos", target_os = "android")))]
+async fn read_platform_hinge_angle() -> Result<f64, Error> {
+    Err(Error::Unsupported)
 }
 
 #[tauri::command]
-async fn read_hinge_angle() -> Result<f64, String> {
+async fn read_hinge_angle() -> Result<f64, Error> {
     read_platform_hinge_angle().await
 }
 
 #[tauri::command]
-async fn read_posture_type() -> Result<String, String> {
+async fn read_posture_type() -> Result<String, Error> {
     let angle = read_hinge_angle().await?;
     let posture = PostureType::from_angle(angle);
     Ok(posture.to_string())
 }
 
+/// Holds the background hinge-watching task, if one is running.
+///
+/// Managed as app state so `start_hinge_watch`/`stop_hinge_watch` can
+/// replace or cancel it without the frontend needing to track a handle.
+#[derive(Default)]
+struct HingeWatch {
+    task: Mutex<Option<JoinHandle<()>>>,
+}
+
+/// Floor for `start_hinge_watch`'s `interval_ms`, so a near-zero value
+/// can't turn the watcher into a tight read/emit loop that hammers the
+/// sensor actor every scheduler tick.
+const MIN_HINGE_WATCH_INTERVAL_MS: u64 = 200;
+
+/// Samples `read_platform_hinge_angle()` on an interval and emits
+/// `hinge-angle`/`posture-changed` events only when the value changes,
+/// so listeners don't have to poll the pull-only commands themselves.
+async fn watch_hinge(app_handle: AppHandle, interval_ms: u64) {
+    let mut last_angle: Option<f64> = None;
+    let mut tracker = PostureTracker::default();
+    let mut last_posture: Option<PostureType> = None;
+
+    loop {
+        if let Ok(angle) = read_platform_hinge_angle().await {
+            if last_angle != Some(angle) {
+                last_angle = Some(angle);
+                let _ = app_handle.emit("hinge-angle", angle);
+            }
+
+            let posture = tracker.next(angle);
+            if last_posture != Some(posture) {
+                last_posture = Some(posture);
+                let _ = app_handle.emit("posture-changed", posture.to_string());
+            }
+        }
+
+        tokio::time::sleep(Duration::from_millis(interval_ms)).await;
+    }
+}
+
+#[tauri::command]
+async fn start_hinge_watch(
+    app_handle: AppHandle,
+    watch: State<'_, HingeWatch>,
+    interval_ms: u64,
+) -> Result<(), Error> {
+    let interval_ms = interval_ms.max(MIN_HINGE_WATCH_INTERVAL_MS);
+
+    let mut task = watch.task.lock().await;
+    if let Some(existing) = task.take() {
+        existing.abort();
+    }
+    *task = Some(tauri::async_runtime::spawn(watch_hinge(
+        app_handle,
+        interval_ms,
+    )));
+    Ok(())
+}
+
+#[tauri::command]
+async fn stop_hinge_watch(watch: State<'_, HingeWatch>) -> Result<(), Error> {
+    if let Some(existing) = watch.task.lock().await.take() {
+        existing.abort();
+    }
+    Ok(())
+}
+
 #[cfg_attr(mobile, tauri::mobile_entry_point)]
 pub fn run() {
     tauri::Builder::default()
         .plugin(tauri_plugin_opener::init())
         .invoke_handler(tauri::generate_handler![
             read_hinge_angle,
-            read_posture_type
+            read_posture_type,
+            start_hinge_watch,
+            stop_hinge_watch
         ])
+        .setup(|app| {
+            app.manage(HingeWatch::default());
+            Ok(())
+        })
         .run(tauri::generate_context!())
         .expect("error while running tauri application");
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn same_angle_never_transitions() {
+        let mut tracker = PostureTracker::default();
+        assert_eq!(tracker.next(180.0), PostureType::Continuous);
+        for _ in 0..5 {
+            assert_eq!(tracker.next(180.0), PostureType::Continuous);
+        }
+    }
+
+    #[test]
+    fn stays_in_posture_within_margin_of_boundary() {
+        let mut tracker = PostureTracker::default();
+        assert_eq!(tracker.next(188.0), PostureType::Continuous);
+        // 193.0 is past the raw 190.0 boundary but within the default 5deg margin.
+        assert_eq!(tracker.next(193.0), PostureType::Continuous);
+    }
+
+    #[test]
+    fn transitions_once_margin_is_exceeded() {
+        let mut tracker = PostureTracker::default();
+        assert_eq!(tracker.next(188.0), PostureType::Continuous);
+        assert_eq!(tracker.next(196.0), PostureType::Flipped);
+    }
+
+    #[test]
+    fn handles_wrap_around_from_folded_through_zero() {
+        let mut tracker = PostureTracker::default();
+        assert_eq!(tracker.next(355.0), PostureType::Folded);
+        // Still within the wrapped Folded margin on the other side of 0/360.
+        assert_eq!(tracker.next(2.0), PostureType::Folded);
+        assert_eq!(tracker.next(40.0), PostureType::HalfOpened);
+    }
+}
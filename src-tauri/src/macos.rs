@@ -0,0 +1,64 @@
+//! Runs the macOS hinge sensor on a dedicated actor thread.
+//!
+//! Some IOKit sensor APIs expect to be touched from a single consistent
+//! thread, which a `Mutex<Hinge>` shared across arbitrary async tasks
+//! can't guarantee. Instead a long-lived thread owns the `Hinge`
+//! instance and answers `AngleRequest`s sent over an unbounded channel,
+//! giving the sensor correct thread affinity while the command side
+//! stays fully async.
+
+use std::sync::OnceLock;
+
+use hinge_angle::macos::Hinge;
+use tokio::sync::{mpsc, oneshot};
+
+use crate::Error;
+
+struct AngleRequest {
+    respond: oneshot::Sender<Result<f64, Error>>,
+}
+
+fn sensor_actor() -> &'static mpsc::UnboundedSender<AngleRequest> {
+    static SENDER: OnceLock<mpsc::UnboundedSender<AngleRequest>> = OnceLock::new();
+
+    SENDER.get_or_init(|| {
+        let (tx, rx) = mpsc::unbounded_channel();
+        std::thread::Builder::new()
+            .name("kami-hinge-sensor".into())
+            .spawn(move || run_actor(rx))
+            .expect("failed to spawn hinge sensor thread");
+        tx
+    })
+}
+
+/// Owns the `Hinge` instance for the actor thread's lifetime, answering
+/// one `AngleRequest` at a time.
+fn run_actor(mut requests: mpsc::UnboundedReceiver<AngleRequest>) {
+    let hinge = Hinge::new();
+
+    while let Some(AngleRequest { respond }) = requests.blocking_recv() {
+        let result = hinge
+            .as_ref()
+            .map_err(|err| Error::SensorInit(err.to_string()))
+            .and_then(|hinge| {
+                hinge
+                    .angle()
+                    .map(|angle| angle as f64)
+                    .map_err(|err| Error::Read(err.to_string()))
+            });
+        let _ = respond.send(result);
+    }
+}
+
+/// Sends an `AngleRequest` to the sensor actor thread and awaits its reply.
+pub(crate) async fn read_angle() -> Result<f64, Error> {
+    let (respond, reply) = oneshot::channel();
+
+    sensor_actor()
+        .send(AngleRequest { respond })
+        .map_err(|_| Error::Read("hinge sensor thread is not running".into()))?;
+
+    reply
+        .await
+        .map_err(|_| Error::Read("hinge sensor thread dropped the request".into()))?
+}